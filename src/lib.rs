@@ -5,6 +5,8 @@
 #[cfg(test)]
 extern crate test;
 
+use std::cmp;
+use std::cmp::Ordering;
 use std::cmp::Ordering::*;
 use std::iter::Peekable;
 use std::collections::{
@@ -28,6 +30,19 @@ pub trait OrderedMapIterator: Iterator<Item=(<Self as OrderedMapIterator>::Key,
         }
     }
 
+    /// join two ordered maps together, using `cmp` to compare keys instead
+    /// of requiring `Key: Ord`. `cmp` must agree with the order both maps
+    /// are already sorted under.
+    fn inner_join_map_by<I, F>(self, map: I, cmp: F) -> InnerJoinMapByIterator<Self, I, F>
+    where I: OrderedMapIterator<Key=Self::Key>,
+          F: FnMut(&Self::Key, &Self::Key) -> Ordering {
+        InnerJoinMapByIterator {
+            a: self,
+            b: map,
+            cmp: cmp
+        }
+    }
+
     /// filter an ordered map with an ordered set
     fn inner_join_set<I>(self, set: I) -> InnerJoinMapSetIterator<Self, I>
     where I: OrderedSetIterator<Item=Self::Key> {
@@ -51,6 +66,82 @@ pub trait OrderedMapIterator: Iterator<Item=(<Self as OrderedMapIterator>::Key,
             right: right.peekable()
         }
     }
+
+    /// join an ordered iterator with the right ordered iterator, using
+    /// `cmp` to compare keys instead of requiring `Key: Ord`. `cmp` must
+    /// agree with the order both sides are already sorted under.
+    fn outer_join_by<I, F>(self, right: I, cmp: F) -> OuterJoinByIterator<Self, I, F>
+    where I: OrderedMapIterator<Key=Self::Key>,
+          F: FnMut(&Self::Key, &Self::Key) -> Ordering {
+        OuterJoinByIterator {
+            left: self.peekable(),
+            right: right.peekable(),
+            cmp: cmp
+        }
+    }
+
+    /// join an ordered iterator with the right ordered iterator, keeping
+    /// every key from the left side. Keys also present on the right are
+    /// attached as `Some(b)`, all others as `None`; right-only keys are
+    /// discarded entirely.
+    fn left_outer_join<I>(self, right: I) -> LeftOuterJoinIterator<Self, I>
+    where I: OrderedMapIterator<Key=Self::Key> {
+        LeftOuterJoinIterator {
+            left: self.peekable(),
+            right: right.peekable()
+        }
+    }
+
+    /// join an ordered iterator with the right ordered iterator, keeping
+    /// every key from the right side. Keys also present on the left are
+    /// attached as `Some(a)`, all others as `None`; left-only keys are
+    /// discarded entirely.
+    fn right_outer_join<I>(self, right: I) -> RightOuterJoinIterator<Self, I>
+    where I: OrderedMapIterator<Key=Self::Key> {
+        RightOuterJoinIterator {
+            left: self.peekable(),
+            right: right.peekable()
+        }
+    }
+
+    /// merge this ordered map with another, yielding every key from both
+    /// in sorted order. Keys present in both are combined with `combine`
+    /// instead of being dropped, so merges can be chained to union three
+    /// or more sorted maps in a single streaming pass.
+    fn merge<I, C>(self, other: I, combine: C) -> MergeIterator<Self, I, C>
+    where I: OrderedMapIterator<Key=Self::Key, Val=Self::Val>,
+          C: FnMut(Self::Val, Self::Val) -> Self::Val {
+        MergeIterator {
+            a: self.peekable(),
+            b: other.peekable(),
+            combine: combine
+        }
+    }
+
+    /// diff this ordered map against a newer ordered map, yielding the
+    /// minimal set of `DiffItem`s needed to turn this map into `new`
+    fn diff<I>(self, new: I) -> DiffIterator<Self, I>
+    where I: OrderedMapIterator<Key=Self::Key> {
+        DiffIterator {
+            old: self.peekable(),
+            new: new.peekable()
+        }
+    }
+}
+
+/// A single difference between two sorted map snapshots, as produced by
+/// `OrderedMapIterator::diff`.
+pub enum DiffItem<K, Old, New> {
+    /// present in the new snapshot but not the old one
+    Added(K, New),
+    /// present in the old snapshot but not the new one
+    Removed(K, Old),
+    /// present in both snapshots but with different values
+    Updated {
+        key: K,
+        old: Old,
+        new: New
+    }
 }
 
 /// Allows an iterator to be do an inner join with another
@@ -74,6 +165,47 @@ pub trait OrderedSetIterator: Iterator + Sized {
             b: map
         }
     }
+
+    /// filter an ordered set with another ordered set, using `cmp` to
+    /// compare items instead of requiring `Item: Ord`. `cmp` must agree
+    /// with the order both sets are already sorted under.
+    fn inner_join_set_by<I, F>(self, map: I, cmp: F) -> InnerJoinSetByIterator<Self, I, F>
+    where I: OrderedSetIterator<Item=Self::Item>,
+          F: FnMut(&Self::Item, &Self::Item) -> Ordering {
+        InnerJoinSetByIterator {
+            a: self,
+            b: map,
+            cmp: cmp
+        }
+    }
+
+    /// union two ordered sets together, collapsing keys present in both
+    /// into a single emission
+    fn union<I>(self, other: I) -> UnionIterator<Self, I>
+    where I: OrderedSetIterator<Item=Self::Item> {
+        UnionIterator {
+            a: self.peekable(),
+            b: other.peekable()
+        }
+    }
+
+    /// the set of items in this iterator that are not present in `other`
+    fn difference<I>(self, other: I) -> DifferenceIterator<Self, I>
+    where I: OrderedSetIterator<Item=Self::Item> {
+        DifferenceIterator {
+            a: self.peekable(),
+            b: other.peekable()
+        }
+    }
+
+    /// the set of items present in exactly one of this iterator or `other`
+    fn symmetric_difference<I>(self, other: I) -> SymmetricDifferenceIterator<Self, I>
+    where I: OrderedSetIterator<Item=Self::Item> {
+        SymmetricDifferenceIterator {
+            a: self.peekable(),
+            b: other.peekable()
+        }
+    }
 }
 
 pub struct InnerJoinMapIterator<A, B> {a: A, b: B}
@@ -83,6 +215,30 @@ pub struct OuterJoinIterator<A: Iterator, B: Iterator> {
     left: Peekable<A>,
     right: Peekable<B>,
 }
+pub struct MergeIterator<A: Iterator, B: Iterator, C> {
+    a: Peekable<A>,
+    b: Peekable<B>,
+    combine: C,
+}
+pub struct InnerJoinMapByIterator<A, B, F> {a: A, b: B, cmp: F}
+pub struct InnerJoinSetByIterator<A, B, F> {a: A, b: B, cmp: F}
+pub struct OuterJoinByIterator<A: Iterator, B: Iterator, F> {
+    left: Peekable<A>,
+    right: Peekable<B>,
+    cmp: F,
+}
+pub struct DiffIterator<A: Iterator, B: Iterator> {old: Peekable<A>, new: Peekable<B>}
+pub struct LeftOuterJoinIterator<A: Iterator, B: Iterator> {
+    left: Peekable<A>,
+    right: Peekable<B>,
+}
+pub struct RightOuterJoinIterator<A: Iterator, B: Iterator> {
+    left: Peekable<A>,
+    right: Peekable<B>,
+}
+pub struct UnionIterator<A: Iterator, B: Iterator> {a: Peekable<A>, b: Peekable<B>}
+pub struct DifferenceIterator<A: Iterator, B: Iterator> {a: Peekable<A>, b: Peekable<B>}
+pub struct SymmetricDifferenceIterator<A: Iterator, B: Iterator> {a: Peekable<A>, b: Peekable<B>}
 
 impl<A, B> Iterator for InnerJoinMapIterator<A, B>
 where A: OrderedMapIterator,
@@ -127,8 +283,81 @@ where A: OrderedMapIterator,
             }
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, a_upper) = self.a.size_hint();
+        let (_, b_upper) = self.b.size_hint();
+        let upper = match (a_upper, b_upper) {
+            (Some(a), Some(b)) => Some(cmp::min(a, b)),
+            _ => None
+        };
+        (0, upper)
+    }
 }
 
+impl<A, B, F> Iterator for InnerJoinMapByIterator<A, B, F>
+where A: OrderedMapIterator,
+      B: OrderedMapIterator<Key=A::Key>,
+      F: FnMut(&A::Key, &A::Key) -> Ordering,
+{
+
+    type Item = (A::Key, (A::Val, B::Val));
+
+    fn next(&mut self) -> Option<(A::Key, (A::Val, B::Val))> {
+        let (mut key_a, mut data_a) = match self.a.next() {
+            None => return None,
+            Some((key, data)) => (key, data)
+        };
+
+        let (mut key_b, mut data_b) = match self.b.next() {
+            None => return None,
+            Some((key, data)) => (key, data)
+        };
+
+        loop {
+            match (self.cmp)(&key_a, &key_b) {
+                Less => {
+                    match self.a.next() {
+                        None => return None,
+                        Some((key, data)) => {
+                            key_a = key;
+                            data_a = data;
+                        }
+                    };
+                },
+                Equal => return Some((key_a, (data_a, data_b))),
+                Greater => {
+                    match self.b.next() {
+                        None => return None,
+                        Some((key, data)) => {
+                            key_b = key;
+                            data_b = data;
+                        }
+                    };
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, a_upper) = self.a.size_hint();
+        let (_, b_upper) = self.b.size_hint();
+        let upper = match (a_upper, b_upper) {
+            (Some(a), Some(b)) => Some(cmp::min(a, b)),
+            _ => None
+        };
+        (0, upper)
+    }
+}
+
+impl<A, B, F> OrderedMapIterator for InnerJoinMapByIterator<A, B, F>
+where A: OrderedMapIterator,
+      B: OrderedMapIterator<Key=A::Key>,
+      F: FnMut(&A::Key, &A::Key) -> Ordering,
+{
+    type Key = A::Key;
+    type Val = (A::Val, B::Val);
+}
 
 impl<A, B> Iterator for InnerJoinSetIterator<A, B>
 where A: OrderedSetIterator,
@@ -167,8 +396,73 @@ where A: OrderedSetIterator,
             }
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, a_upper) = self.a.size_hint();
+        let (_, b_upper) = self.b.size_hint();
+        let upper = match (a_upper, b_upper) {
+            (Some(a), Some(b)) => Some(cmp::min(a, b)),
+            _ => None
+        };
+        (0, upper)
+    }
 }
 
+impl<A, B, F> Iterator for InnerJoinSetByIterator<A, B, F>
+where A: OrderedSetIterator,
+      B: OrderedSetIterator<Item=A::Item>,
+      F: FnMut(&A::Item, &A::Item) -> Ordering,
+{
+
+    type Item = A::Item;
+
+    fn next(&mut self) -> Option<A::Item> {
+        let mut key_a = match self.a.next() {
+            None => return None,
+            Some(key) => key
+        };
+
+        let mut key_b = match self.b.next() {
+            None => return None,
+            Some(key) => key
+        };
+
+        loop {
+            match (self.cmp)(&key_a, &key_b) {
+                Less => {
+                    match self.a.next() {
+                        None => return None,
+                        Some(key) => { key_a = key; }
+                    };
+                },
+                Equal => return Some(key_a),
+                Greater => {
+                    match self.b.next() {
+                        None => return None,
+                        Some(key) => { key_b = key; }
+                    };
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, a_upper) = self.a.size_hint();
+        let (_, b_upper) = self.b.size_hint();
+        let upper = match (a_upper, b_upper) {
+            (Some(a), Some(b)) => Some(cmp::min(a, b)),
+            _ => None
+        };
+        (0, upper)
+    }
+}
+
+impl<A, B, F> OrderedSetIterator for InnerJoinSetByIterator<A, B, F>
+where A: OrderedSetIterator,
+      B: OrderedSetIterator<Item=A::Item>,
+      F: FnMut(&A::Item, &A::Item) -> Ordering,
+{}
+
 impl<MapIter, SetIter> Iterator for InnerJoinMapSetIterator<MapIter, SetIter>
 where SetIter: OrderedSetIterator,
       MapIter: OrderedMapIterator<Key=SetIter::Item>,
@@ -209,6 +503,16 @@ where SetIter: OrderedSetIterator,
             }
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, map_upper) = self.map.size_hint();
+        let (_, set_upper) = self.set.size_hint();
+        let upper = match (map_upper, set_upper) {
+            (Some(a), Some(b)) => Some(cmp::min(a, b)),
+            _ => None
+        };
+        (0, upper)
+    }
 }
 
 impl<A, B> Iterator for OuterJoinIterator<A, B>
@@ -245,6 +549,308 @@ where A: OrderedMapIterator,
             }
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lower, a_upper) = self.left.size_hint();
+        let (b_lower, b_upper) = self.right.size_hint();
+        let lower = cmp::max(a_lower, b_lower);
+        let upper = match (a_upper, b_upper) {
+            (Some(a), Some(b)) => Some(a.saturating_add(b)),
+            _ => None
+        };
+        (lower, upper)
+    }
+}
+
+impl<A, B, F> Iterator for OuterJoinByIterator<A, B, F>
+where A: OrderedMapIterator,
+      B: OrderedMapIterator<Key=A::Key>,
+      F: FnMut(&A::Key, &A::Key) -> Ordering,
+{
+
+    type Item = (A::Key, (Option<A::Val>, Option<B::Val>));
+
+    fn next(&mut self) -> Option<(A::Key, (Option<A::Val>, Option<B::Val>))> {
+        let which = match (self.left.peek(), self.right.peek()) {
+            (Some(&(ref ka, _)), Some(&(ref kb, _))) => (self.cmp)(kb, ka),
+            (None, Some(_)) => Less,
+            (Some(_), None) => Greater,
+            (None, None) => return None
+        };
+
+        match which {
+            Equal => {
+                let ((k, a), (_, b)) =
+                    (self.left.next().expect("no value found"),
+                     self.right.next().expect("no value found"));
+
+                Some((k, (Some(a), Some(b))))
+            }
+            Less => {
+                let (k, v) = self.right.next().expect("no value found");
+                Some((k, (None, Some(v))))
+            }
+            Greater => {
+                let (k, v) = self.left.next().expect("no value found");
+                Some((k, (Some(v), None)))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lower, a_upper) = self.left.size_hint();
+        let (b_lower, b_upper) = self.right.size_hint();
+        let lower = cmp::max(a_lower, b_lower);
+        let upper = match (a_upper, b_upper) {
+            (Some(a), Some(b)) => Some(a.saturating_add(b)),
+            _ => None
+        };
+        (lower, upper)
+    }
+}
+
+impl<A, B> Iterator for LeftOuterJoinIterator<A, B>
+where A: OrderedMapIterator,
+      B: OrderedMapIterator<Key=A::Key>,
+      A::Key: Ord + Eq,
+{
+
+    type Item = (A::Key, (A::Val, Option<B::Val>));
+
+    fn next(&mut self) -> Option<(A::Key, (A::Val, Option<B::Val>))> {
+        let (key_a, val_a) = match self.left.next() {
+            None => return None,
+            Some((k, v)) => (k, v)
+        };
+
+        loop {
+            let which = match self.right.peek() {
+                Some(&(ref kb, _)) => kb.cmp(&key_a),
+                None => Greater
+            };
+
+            match which {
+                Less => { self.right.next(); },
+                Equal => {
+                    let (_, vb) = self.right.next().expect("no value found");
+                    return Some((key_a, (val_a, Some(vb))));
+                }
+                Greater => return Some((key_a, (val_a, None)))
+            }
+        }
+    }
+}
+
+impl<A, B> Iterator for RightOuterJoinIterator<A, B>
+where A: OrderedMapIterator,
+      B: OrderedMapIterator<Key=A::Key>,
+      A::Key: Ord + Eq,
+{
+
+    type Item = (A::Key, (Option<A::Val>, B::Val));
+
+    fn next(&mut self) -> Option<(A::Key, (Option<A::Val>, B::Val))> {
+        let (key_b, val_b) = match self.right.next() {
+            None => return None,
+            Some((k, v)) => (k, v)
+        };
+
+        loop {
+            let which = match self.left.peek() {
+                Some(&(ref ka, _)) => ka.cmp(&key_b),
+                None => Greater
+            };
+
+            match which {
+                Less => { self.left.next(); },
+                Equal => {
+                    let (_, va) = self.left.next().expect("no value found");
+                    return Some((key_b, (Some(va), val_b)));
+                }
+                Greater => return Some((key_b, (None, val_b)))
+            }
+        }
+    }
+}
+
+impl<A, B> Iterator for UnionIterator<A, B>
+where A: OrderedSetIterator,
+      B: OrderedSetIterator<Item=A::Item>,
+      A::Item: Ord,
+{
+
+    type Item = A::Item;
+
+    fn next(&mut self) -> Option<A::Item> {
+        let which = match (self.a.peek(), self.b.peek()) {
+            (Some(ka), Some(kb)) => ka.cmp(kb),
+            (Some(_), None) => Less,
+            (None, Some(_)) => Greater,
+            (None, None) => return None
+        };
+
+        match which {
+            Less => self.a.next(),
+            Greater => self.b.next(),
+            Equal => {
+                self.b.next();
+                self.a.next()
+            }
+        }
+    }
+}
+
+impl<A, B> Iterator for DifferenceIterator<A, B>
+where A: OrderedSetIterator,
+      B: OrderedSetIterator<Item=A::Item>,
+      A::Item: Ord,
+{
+
+    type Item = A::Item;
+
+    fn next(&mut self) -> Option<A::Item> {
+        loop {
+            let which = match (self.a.peek(), self.b.peek()) {
+                (Some(ka), Some(kb)) => ka.cmp(kb),
+                (Some(_), None) => return self.a.next(),
+                (None, _) => return None
+            };
+
+            match which {
+                Less => return self.a.next(),
+                Equal => {
+                    self.a.next();
+                    self.b.next();
+                },
+                Greater => { self.b.next(); }
+            }
+        }
+    }
+}
+
+impl<A, B> Iterator for SymmetricDifferenceIterator<A, B>
+where A: OrderedSetIterator,
+      B: OrderedSetIterator<Item=A::Item>,
+      A::Item: Ord,
+{
+
+    type Item = A::Item;
+
+    fn next(&mut self) -> Option<A::Item> {
+        loop {
+            let which = match (self.a.peek(), self.b.peek()) {
+                (Some(ka), Some(kb)) => ka.cmp(kb),
+                (Some(_), None) => return self.a.next(),
+                (None, Some(_)) => return self.b.next(),
+                (None, None) => return None
+            };
+
+            match which {
+                Less => return self.a.next(),
+                Greater => return self.b.next(),
+                Equal => {
+                    self.a.next();
+                    self.b.next();
+                }
+            }
+        }
+    }
+}
+
+impl<A, B> OrderedSetIterator for UnionIterator<A, B>
+where A: OrderedSetIterator,
+      B: OrderedSetIterator<Item=A::Item>,
+      A::Item: Ord,
+{}
+
+impl<A, B> OrderedSetIterator for DifferenceIterator<A, B>
+where A: OrderedSetIterator,
+      B: OrderedSetIterator<Item=A::Item>,
+      A::Item: Ord,
+{}
+
+impl<A, B> OrderedSetIterator for SymmetricDifferenceIterator<A, B>
+where A: OrderedSetIterator,
+      B: OrderedSetIterator<Item=A::Item>,
+      A::Item: Ord,
+{}
+
+impl<A, B> Iterator for DiffIterator<A, B>
+where A: OrderedMapIterator,
+      B: OrderedMapIterator<Key=A::Key>,
+      A::Key: Ord,
+      A::Val: PartialEq<B::Val>,
+{
+
+    type Item = DiffItem<A::Key, A::Val, B::Val>;
+
+    fn next(&mut self) -> Option<DiffItem<A::Key, A::Val, B::Val>> {
+        loop {
+            let which = match (self.old.peek(), self.new.peek()) {
+                (Some(&(ref ka, _)), Some(&(ref kb, _))) => ka.cmp(kb),
+                (Some(_), None) => Less,
+                (None, Some(_)) => Greater,
+                (None, None) => return None
+            };
+
+            match which {
+                Less => {
+                    let (k, v) = self.old.next().expect("no value found");
+                    return Some(DiffItem::Removed(k, v));
+                }
+                Greater => {
+                    let (k, v) = self.new.next().expect("no value found");
+                    return Some(DiffItem::Added(k, v));
+                }
+                Equal => {
+                    let (key, old) = self.old.next().expect("no value found");
+                    let (_, new) = self.new.next().expect("no value found");
+                    if old != new {
+                        return Some(DiffItem::Updated { key: key, old: old, new: new });
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<A, B, C> Iterator for MergeIterator<A, B, C>
+where A: OrderedMapIterator,
+      B: OrderedMapIterator<Key=A::Key, Val=A::Val>,
+      A::Key: Ord,
+      C: FnMut(A::Val, A::Val) -> A::Val,
+{
+
+    type Item = (A::Key, A::Val);
+
+    fn next(&mut self) -> Option<(A::Key, A::Val)> {
+        let which = match (self.a.peek(), self.b.peek()) {
+            (Some(&(ref ka, _)), Some(&(ref kb, _))) => ka.cmp(kb),
+            (Some(_), None) => Less,
+            (None, Some(_)) => Greater,
+            (None, None) => return None
+        };
+
+        match which {
+            Less => self.a.next(),
+            Greater => self.b.next(),
+            Equal => {
+                let (k, va) = self.a.next().expect("no value found");
+                let (_, vb) = self.b.next().expect("no value found");
+                Some((k, (self.combine)(va, vb)))
+            }
+        }
+    }
+}
+
+impl<A, B, C> OrderedMapIterator for MergeIterator<A, B, C>
+where A: OrderedMapIterator,
+      B: OrderedMapIterator<Key=A::Key, Val=A::Val>,
+      A::Key: Ord,
+      C: FnMut(A::Val, A::Val) -> A::Val,
+{
+    type Key = A::Key;
+    type Val = A::Val;
 }
 
 impl<'a, K: Ord> OrderedSetIterator for btree_set::Iter<'a, K> {}
@@ -301,7 +907,7 @@ mod tests {
     use test::Bencher;
     use test;
 
-    use super::{OrderedSetIterator, OrderedMapIterator};
+    use super::{OrderedSetIterator, OrderedMapIterator, DiffItem};
 
     #[test]
     fn join_two_sets() {
@@ -341,6 +947,82 @@ mod tests {
         assert_eq!(expected, powers_of_two_and_three);
     }
 
+    #[test]
+    fn union_two_sets() {
+        use std::collections::BTreeSet;
+
+        let a: BTreeSet<i32> = vec![1, 2, 4, 6, 8].into_iter().collect();
+        let b: BTreeSet<i32> = vec![2, 3, 4, 5].into_iter().collect();
+
+        let expected = vec![1, 2, 3, 4, 5, 6, 8];
+
+        let union: Vec<i32> = a.iter().union(b.iter()).map(|&x| x).collect();
+
+        assert_eq!(expected, union);
+    }
+
+    #[test]
+    fn difference_two_sets() {
+        use std::collections::BTreeSet;
+
+        let a: BTreeSet<i32> = vec![1, 2, 4, 6, 8].into_iter().collect();
+        let b: BTreeSet<i32> = vec![2, 3, 4, 5].into_iter().collect();
+
+        let expected = vec![1, 6, 8];
+
+        let difference: Vec<i32> = a.iter().difference(b.iter()).map(|&x| x).collect();
+
+        assert_eq!(expected, difference);
+    }
+
+    #[test]
+    fn symmetric_difference_two_sets() {
+        use std::collections::BTreeSet;
+
+        let a: BTreeSet<i32> = vec![1, 2, 4, 6, 8].into_iter().collect();
+        let b: BTreeSet<i32> = vec![2, 3, 4, 5].into_iter().collect();
+
+        let expected = vec![1, 3, 5, 6, 8];
+
+        let symmetric_difference: Vec<i32> =
+            a.iter().symmetric_difference(b.iter()).map(|&x| x).collect();
+
+        assert_eq!(expected, symmetric_difference);
+    }
+
+    #[test]
+    fn union_collapses_duplicate_keys() {
+        use std::collections::BTreeSet;
+
+        let a: BTreeSet<i32> = vec![1, 2, 3].into_iter().collect();
+        let b: BTreeSet<i32> = vec![1, 2, 3].into_iter().collect();
+
+        let expected = vec![1, 2, 3];
+
+        let union: Vec<i32> = a.iter().union(b.iter()).map(|&x| x).collect();
+
+        assert_eq!(expected, union);
+    }
+
+    #[test]
+    fn set_algebra_one_side_exhausted_first() {
+        use std::collections::BTreeSet;
+
+        // `a` is disjoint from, and exhausts well before, `b`
+        let a: BTreeSet<i32> = vec![1, 3].into_iter().collect();
+        let b: BTreeSet<i32> = vec![2, 4, 6, 8, 10].into_iter().collect();
+
+        let union: Vec<i32> = a.iter().union(b.iter()).map(|&x| x).collect();
+        assert_eq!(vec![1, 2, 3, 4, 6, 8, 10], union);
+
+        let difference: Vec<i32> = a.iter().difference(b.iter()).map(|&x| x).collect();
+        assert_eq!(vec![1, 3], difference);
+
+        let symmetric_difference: Vec<i32> =
+            a.iter().symmetric_difference(b.iter()).map(|&x| x).collect();
+        assert_eq!(vec![1, 2, 3, 4, 6, 8, 10], symmetric_difference);
+    }
+
     #[test]
     fn join_two_maps() {
         use std::collections::BTreeMap;
@@ -375,6 +1057,110 @@ mod tests {
         assert_eq!(None, powers_of_two_and_three.next());
     }
 
+    #[test]
+    fn merge_three_maps_combining_counts() {
+        use std::collections::BTreeMap;
+
+        let a: BTreeMap<i32, i32> = vec![(1, 1), (2, 1)].into_iter().collect();
+        let b: BTreeMap<i32, i32> = vec![(2, 1), (3, 1)].into_iter().collect();
+        let c: BTreeMap<i32, i32> = vec![(1, 1), (3, 1), (4, 1)].into_iter().collect();
+
+        let merged: Vec<(i32, i32)> =
+            a.into_iter().merge(b.into_iter(), |x, y| x + y)
+            .merge(c.into_iter(), |x, y| x + y)
+            .collect();
+
+        assert_eq!(vec![(1, 2), (2, 2), (3, 2), (4, 1)], merged);
+    }
+
+    #[test]
+    fn inner_join_map_by_projects_composite_key() {
+        use std::collections::BTreeMap;
+
+        // keys are (group, version) pairs; join only on the group component
+        let a: BTreeMap<(i32, i32), i32> =
+            vec![((1, 0), 10), ((2, 0), 20), ((3, 0), 30)].into_iter().collect();
+        let b: BTreeMap<(i32, i32), i32> =
+            vec![((2, 7), 200), ((3, 7), 300), ((4, 7), 400)].into_iter().collect();
+
+        let joined: Vec<((i32, i32), (i32, i32))> =
+            a.iter()
+            .inner_join_map_by(b.iter(), |&(ga, _), &(gb, _)| ga.cmp(&gb))
+            .map(|(&k, (&va, &vb))| (k, (va, vb)))
+            .collect();
+
+        assert_eq!(vec![((2, 0), (20, 200)), ((3, 0), (30, 300))], joined);
+    }
+
+    #[test]
+    fn inner_join_set_by_projects_composite_key() {
+        use std::collections::BTreeSet;
+
+        let a: BTreeSet<(i32, i32)> = vec![(1, 0), (2, 0), (3, 0)].into_iter().collect();
+        let b: BTreeSet<(i32, i32)> = vec![(2, 7), (3, 7), (4, 7)].into_iter().collect();
+
+        let joined: Vec<i32> =
+            a.iter()
+            .inner_join_set_by(b.iter(), |&(ga, _), &(gb, _)| ga.cmp(&gb))
+            .map(|&(group, _)| group)
+            .collect();
+
+        assert_eq!(vec![2, 3], joined);
+    }
+
+    #[test]
+    fn outer_join_by_projects_composite_key() {
+        use std::collections::BTreeMap;
+
+        let a: BTreeMap<(i32, i32), i32> = vec![((1, 0), 10), ((2, 0), 20)].into_iter().collect();
+        let b: BTreeMap<(i32, i32), i32> = vec![((2, 7), 200), ((3, 7), 300)].into_iter().collect();
+
+        let joined: Vec<(i32, (Option<i32>, Option<i32>))> =
+            a.iter()
+            .outer_join_by(b.iter(), |&(ga, _), &(gb, _)| ga.cmp(&gb))
+            .map(|(&(group, _), (va, vb))| (group, (va.map(|&x| x), vb.map(|&x| x))))
+            .collect();
+
+        let expected = vec![
+            (1, (Some(10), None)),
+            (2, (Some(20), Some(200))),
+            (3, (None, Some(300)))
+        ];
+
+        assert_eq!(expected, joined);
+    }
+
+    #[test]
+    fn diff_detects_added_removed_and_updated() {
+        use std::collections::BTreeMap;
+
+        let mut old = BTreeMap::new();
+        old.insert(1, 10);
+        old.insert(2, 20);
+        old.insert(3, 30);
+
+        let mut new = BTreeMap::new();
+        new.insert(2, 200); // updated
+        new.insert(3, 30);  // unchanged, should not appear in the diff
+        new.insert(4, 40);  // added
+
+        let diffs: Vec<String> = old.into_iter().diff(new.into_iter()).map(|item| {
+            match item {
+                DiffItem::Added(k, v) => format!("Added({}, {})", k, v),
+                DiffItem::Removed(k, v) => format!("Removed({}, {})", k, v),
+                DiffItem::Updated { key, old, new } => format!("Updated({}, {}, {})", key, old, new)
+            }
+        }).collect();
+
+        let expected = vec![
+            "Removed(1, 10)".to_string(),
+            "Updated(2, 20, 200)".to_string(),
+            "Added(4, 40)".to_string()
+        ];
+
+        assert_eq!(expected, diffs);
+    }
+
     #[test]
     fn outer_join_fizz_buzz() {
         use std::collections::BTreeMap;
@@ -408,6 +1194,91 @@ mod tests {
         }
     }
 
+    #[test]
+    fn left_outer_join_keeps_all_left_keys() {
+        use std::collections::BTreeMap;
+
+        let threes: BTreeMap<i32, i32> = vec![(1, 10), (2, 20), (3, 30)].into_iter().collect();
+        let fives: BTreeMap<i32, i32> = vec![(2, 200), (4, 400)].into_iter().collect();
+
+        let joined: Vec<(i32, (i32, Option<i32>))> =
+            threes.iter()
+            .left_outer_join(fives.iter())
+            .map(|(&k, (&a, b))| (k, (a, b.map(|&x| x))))
+            .collect();
+
+        // key 4 is right-only and is dropped entirely
+        let expected = vec![(1, (10, None)), (2, (20, Some(200))), (3, (30, None))];
+
+        assert_eq!(expected, joined);
+    }
+
+    #[test]
+    fn right_outer_join_keeps_all_right_keys() {
+        use std::collections::BTreeMap;
+
+        let threes: BTreeMap<i32, i32> = vec![(1, 10), (2, 20), (3, 30)].into_iter().collect();
+        let fives: BTreeMap<i32, i32> = vec![(2, 200), (4, 400)].into_iter().collect();
+
+        let joined: Vec<(i32, (Option<i32>, i32))> =
+            threes.iter()
+            .right_outer_join(fives.iter())
+            .map(|(&k, (a, &b))| (k, (a.map(|&x| x), b)))
+            .collect();
+
+        // keys 1 and 3 are left-only and are dropped entirely
+        let expected = vec![(2, (Some(20), 200)), (4, (None, 400))];
+
+        assert_eq!(expected, joined);
+    }
+
+    #[test]
+    fn inner_join_map_size_hint() {
+        use std::collections::BTreeMap;
+
+        let powers_of_two: BTreeMap<i32, i32> = range(1, 10).map(|x| (x * 2, x)).collect();
+        let powers_of_three: BTreeMap<i32, i32> = range(1, 10).map(|x| (x * 3, x)).collect();
+
+        let iter = powers_of_two.iter().inner_join_map(powers_of_three.iter());
+
+        assert_eq!((0, Some(9)), iter.size_hint());
+    }
+
+    #[test]
+    fn inner_join_set_size_hint() {
+        use std::collections::BTreeSet;
+
+        let powers_of_two: BTreeSet<i32> = range(1, 10).map(|x| x * 2).collect();
+        let powers_of_three: BTreeSet<i32> = range(1, 10).map(|x| x * 3).collect();
+
+        let iter = powers_of_two.iter().inner_join_set(powers_of_three.iter());
+
+        assert_eq!((0, Some(9)), iter.size_hint());
+    }
+
+    #[test]
+    fn inner_join_map_set_size_hint() {
+        use std::collections::{BTreeMap, BTreeSet};
+
+        let powers_of_two: BTreeSet<i32> = range(1, 10).map(|x| x * 2).collect();
+        let powers_of_three: BTreeMap<i32, i32> = range(1, 10).map(|x| (x * 3, x)).collect();
+
+        let iter = powers_of_two.iter().inner_join_map(powers_of_three.iter());
+
+        assert_eq!((0, Some(9)), iter.size_hint());
+    }
+
+    #[test]
+    fn outer_join_size_hint() {
+        use std::collections::BTreeMap;
+
+        let mul_of_three: BTreeMap<i32, i32> = range(0, 100).map(|x| (x*3, x)).collect();
+        let mul_of_five: BTreeMap<i32, i32> = range(0, 100).map(|x| (x*5, x)).collect();
+
+        let iter = mul_of_three.iter().outer_join(mul_of_five.iter());
+
+        assert_eq!((100, Some(200)), iter.size_hint());
+    }
 
     #[bench]
     pub fn inner_join_map(b: &mut test::Bencher) {